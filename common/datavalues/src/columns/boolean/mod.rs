@@ -20,6 +20,8 @@ use common_arrow::arrow::bitmap::utils::BitChunksExact;
 use common_arrow::arrow::bitmap::Bitmap;
 use common_arrow::arrow::datatypes::DataType as ArrowType;
 use common_arrow::bitmap::MutableBitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
 
 use crate::prelude::*;
 
@@ -64,6 +66,313 @@ impl BooleanColumn {
     pub fn values(&self) -> &Bitmap {
         &self.values
     }
+
+    /// Builds a `BooleanColumn` of `length` rows from `f(index)`, the hot path for
+    /// predicate/comparison kernels. Results are accumulated word-at-a-time into a
+    /// `u64` and flushed every 64 rows, instead of pushing one bit at a time.
+    pub fn collect_bool(length: usize, mut f: impl FnMut(usize) -> bool) -> Self {
+        let mut bitmap = MutableBitmap::with_capacity(length);
+        let mut index = 0;
+        let chunks = length / 64;
+
+        for _ in 0..chunks {
+            let mut word = 0u64;
+            for bit in 0..64 {
+                if f(index) {
+                    word |= 1 << bit;
+                }
+                index += 1;
+            }
+            bitmap.extend_from_slice(&word.to_le_bytes(), 0, 64);
+        }
+
+        let remainder = length - index;
+        if remainder > 0 {
+            let mut word = 0u64;
+            for bit in 0..remainder {
+                if f(index) {
+                    word |= 1 << bit;
+                }
+                index += 1;
+            }
+            bitmap.extend_from_slice(&word.to_le_bytes(), 0, remainder);
+        }
+
+        BooleanColumn {
+            values: bitmap.into(),
+        }
+    }
+
+    /// Builds a column by choosing, element-wise, from `truthy` where `self` is
+    /// true and from `falsy` otherwise. Length-1 arguments are broadcast to `self.len()`.
+    pub fn if_then_else(&self, truthy: &ColumnRef, falsy: &ColumnRef) -> Result<ColumnRef> {
+        let len = self.len();
+        let truthy = if truthy.len() == 1 {
+            truthy.replicate(&[len])
+        } else {
+            truthy.clone()
+        };
+        let falsy = if falsy.len() == 1 {
+            falsy.replicate(&[len])
+        } else {
+            falsy.clone()
+        };
+
+        if truthy.len() != len || falsy.len() != len {
+            return Err(ErrorCode::BadArguments(format!(
+                "if_then_else: truthy and falsy columns must have length {} or 1, got {} and {}",
+                len,
+                truthy.len(),
+                falsy.len()
+            )));
+        }
+
+        if let (Some(t), Some(f)) = (
+            truthy.as_any().downcast_ref::<BooleanColumn>(),
+            falsy.as_any().downcast_ref::<BooleanColumn>(),
+        ) {
+            return Ok(self.if_then_else_boolean(t, f));
+        }
+
+        // `BitChunksExact` assumes the slice it is given starts at logical bit 0,
+        // so any bitmap with a sub-byte slice offset must be realigned first.
+        let pred = Self::realign(&self.values);
+        let (pred_slice, pred_offset, pred_length) = pred.as_slice();
+        debug_assert_eq!(pred_offset, 0);
+        let mut pred_chunks = BitChunksExact::<u64>::new(pred_slice, pred_length);
+
+        // Runs of consecutive fully-true/fully-false chunks are bulk-copied by
+        // slicing the source column directly (a zero-copy reslice of its
+        // underlying buffer) instead of reading the source element-by-element;
+        // only chunks with a genuine mix of predicate bits fall back to
+        // per-row `DataValue` dispatch.
+        let mut segments: Vec<ColumnRef> = Vec::new();
+        let mut pending_is_truthy = true;
+        let mut pending_start = 0usize;
+        let mut pending_len = 0usize;
+        let mut offset = 0usize;
+
+        for pred_chunk in pred_chunks.by_ref() {
+            if pred_chunk == u64::MAX || pred_chunk == 0 {
+                let is_truthy = pred_chunk == u64::MAX;
+                if pending_len > 0 && pending_is_truthy == is_truthy {
+                    pending_len += 64;
+                } else {
+                    if pending_len > 0 {
+                        let src = if pending_is_truthy { &truthy } else { &falsy };
+                        segments.push(src.slice(pending_start, pending_len));
+                    }
+                    pending_is_truthy = is_truthy;
+                    pending_start = offset;
+                    pending_len = 64;
+                }
+            } else {
+                if pending_len > 0 {
+                    let src = if pending_is_truthy { &truthy } else { &falsy };
+                    segments.push(src.slice(pending_start, pending_len));
+                    pending_len = 0;
+                }
+                let mut chunk_builder = truthy.data_type().create_mutable(64);
+                for i in 0..64 {
+                    let value = if pred_chunk & (1 << i) != 0 {
+                        truthy.get(offset + i)
+                    } else {
+                        falsy.get(offset + i)
+                    };
+                    chunk_builder.append_data_value(value)?;
+                }
+                segments.push(chunk_builder.to_column());
+            }
+            offset += 64;
+        }
+        if pending_len > 0 {
+            let src = if pending_is_truthy { &truthy } else { &falsy };
+            segments.push(src.slice(pending_start, pending_len));
+        }
+
+        let remainder: Vec<bool> = pred_chunks.remainder_iter().collect();
+        if !remainder.is_empty() {
+            if remainder.iter().all(|is_true| *is_true) {
+                segments.push(truthy.slice(offset, remainder.len()));
+            } else if remainder.iter().all(|is_true| !*is_true) {
+                segments.push(falsy.slice(offset, remainder.len()));
+            } else {
+                let mut tail_builder = truthy.data_type().create_mutable(remainder.len());
+                for (i, is_true) in remainder.iter().enumerate() {
+                    let value = if *is_true {
+                        truthy.get(offset + i)
+                    } else {
+                        falsy.get(offset + i)
+                    };
+                    tail_builder.append_data_value(value)?;
+                }
+                segments.push(tail_builder.to_column());
+            }
+        }
+
+        Series::concat(&segments)
+    }
+
+    /// Boolean-output specialization of `if_then_else`: computed directly on the
+    /// three bitmaps as `(pred & truthy) | (!pred & falsy)` instead of per-row dispatch.
+    fn if_then_else_boolean(&self, truthy: &BooleanColumn, falsy: &BooleanColumn) -> ColumnRef {
+        let pred = Self::realign(&self.values);
+        let t = Self::realign(truthy.values());
+        let f = Self::realign(falsy.values());
+
+        let mut bitmap = MutableBitmap::with_capacity(self.len());
+        let (pred_slice, pred_offset, pred_length) = pred.as_slice();
+        let (t_slice, t_offset, _) = t.as_slice();
+        let (f_slice, f_offset, _) = f.as_slice();
+        debug_assert_eq!((pred_offset, t_offset, f_offset), (0, 0, 0));
+
+        let mut pred_chunks = BitChunksExact::<u64>::new(pred_slice, pred_length);
+        let mut t_chunks = BitChunksExact::<u64>::new(t_slice, pred_length);
+        let mut f_chunks = BitChunksExact::<u64>::new(f_slice, pred_length);
+
+        pred_chunks
+            .by_ref()
+            .zip(t_chunks.by_ref())
+            .zip(f_chunks.by_ref())
+            .for_each(|((pred, t), f)| {
+                let word = (pred & t) | (!pred & f);
+                bitmap.extend_from_slice(&word.to_le_bytes(), 0, 64);
+            });
+
+        pred_chunks
+            .remainder_iter()
+            .zip(t_chunks.remainder_iter())
+            .zip(f_chunks.remainder_iter())
+            .for_each(|((pred, t), f)| {
+                bitmap.push(if pred { t } else { f });
+            });
+
+        Arc::new(BooleanColumn {
+            values: bitmap.into(),
+        })
+    }
+
+    /// Returns `bitmap` unchanged if it already starts at logical bit 0 (the
+    /// only shape `BitChunksExact` can read), otherwise a freshly materialized,
+    /// zero-offset copy — needed for bitmaps sliced at a non-byte-aligned offset.
+    fn realign(bitmap: &Bitmap) -> Bitmap {
+        let (_, offset, _) = bitmap.as_slice();
+        if offset == 0 {
+            bitmap.clone()
+        } else {
+            bitmap.iter().collect()
+        }
+    }
+}
+
+/// `Column`-level entry point for [`BooleanColumn::if_then_else`], so CASE/WHEN
+/// and coalesce can lower onto this kernel without downcasting `predicate`
+/// themselves.
+pub fn if_then_else(
+    predicate: &ColumnRef,
+    truthy: &ColumnRef,
+    falsy: &ColumnRef,
+) -> Result<ColumnRef> {
+    let predicate = predicate.as_any().downcast_ref::<BooleanColumn>().ok_or_else(|| {
+        ErrorCode::BadArguments("if_then_else: predicate column must be Boolean".to_string())
+    })?;
+    predicate.if_then_else(truthy, falsy)
+}
+
+/// Three-valued (Kleene) boolean logic: NULLs are propagated following SQL
+/// truth tables rather than a naive AND of the two validity bitmaps, e.g.
+/// `TRUE OR NULL = TRUE` and `FALSE AND NULL = FALSE`.
+impl BooleanColumn {
+    pub fn kleene_and(
+        lhs: &Bitmap,
+        rhs: &Bitmap,
+        lhs_validity: Option<&Bitmap>,
+        rhs_validity: Option<&Bitmap>,
+    ) -> (Bitmap, Option<Bitmap>) {
+        let values = lhs & rhs;
+        let validity = match (lhs_validity, rhs_validity) {
+            (None, None) => None,
+            // a side without a validity bitmap is always valid, so it can only
+            // contribute its "valid and false" bits unconditionally.
+            (Some(l), None) => Some(l | &!rhs),
+            (None, Some(r)) => Some(r | &!lhs),
+            (Some(l), Some(r)) => Some((l & &!lhs) | (r & &!rhs) | (l & r)),
+        };
+        (values, validity)
+    }
+
+    pub fn kleene_or(
+        lhs: &Bitmap,
+        rhs: &Bitmap,
+        lhs_validity: Option<&Bitmap>,
+        rhs_validity: Option<&Bitmap>,
+    ) -> (Bitmap, Option<Bitmap>) {
+        let values = lhs | rhs;
+        let validity = match (lhs_validity, rhs_validity) {
+            (None, None) => None,
+            // a side without a validity bitmap is always valid, so it can only
+            // contribute its "valid and true" bits unconditionally.
+            (Some(l), None) => Some(l | rhs),
+            (None, Some(r)) => Some(r | lhs),
+            (Some(l), Some(r)) => Some((l & lhs) | (r & rhs) | (l & r)),
+        };
+        (values, validity)
+    }
+
+    pub fn kleene_not(values: &Bitmap) -> Bitmap {
+        !values
+    }
+}
+
+/// `Column`-level entry points for the Kleene operators above: this is what a
+/// nullable-column binary-operator dispatch calls for `AND`/`OR`/`NOT` on
+/// boolean operands, so NULLs are propagated per SQL truth tables rather than
+/// a naive AND of the two validity bitmaps. Validity is passed in explicitly
+/// since it is tracked by the wrapping nullable column, not by `BooleanColumn`
+/// itself; the caller is expected to apply the returned validity to its own
+/// nullable wrapper.
+pub fn and(
+    lhs: &ColumnRef,
+    lhs_validity: Option<&Bitmap>,
+    rhs: &ColumnRef,
+    rhs_validity: Option<&Bitmap>,
+) -> Result<(ColumnRef, Option<Bitmap>)> {
+    kleene_dispatch(lhs, lhs_validity, rhs, rhs_validity, BooleanColumn::kleene_and)
+}
+
+pub fn or(
+    lhs: &ColumnRef,
+    lhs_validity: Option<&Bitmap>,
+    rhs: &ColumnRef,
+    rhs_validity: Option<&Bitmap>,
+) -> Result<(ColumnRef, Option<Bitmap>)> {
+    kleene_dispatch(lhs, lhs_validity, rhs, rhs_validity, BooleanColumn::kleene_or)
+}
+
+pub fn not(column: &ColumnRef) -> Result<ColumnRef> {
+    let column = column.as_any().downcast_ref::<BooleanColumn>().ok_or_else(|| {
+        ErrorCode::BadArguments("not: column must be Boolean".to_string())
+    })?;
+    Ok(Arc::new(BooleanColumn {
+        values: BooleanColumn::kleene_not(column.values()),
+    }))
+}
+
+fn kleene_dispatch(
+    lhs: &ColumnRef,
+    lhs_validity: Option<&Bitmap>,
+    rhs: &ColumnRef,
+    rhs_validity: Option<&Bitmap>,
+    op: impl Fn(&Bitmap, &Bitmap, Option<&Bitmap>, Option<&Bitmap>) -> (Bitmap, Option<Bitmap>),
+) -> Result<(ColumnRef, Option<Bitmap>)> {
+    let lhs = lhs.as_any().downcast_ref::<BooleanColumn>().ok_or_else(|| {
+        ErrorCode::BadArguments("and/or: left column must be Boolean".to_string())
+    })?;
+    let rhs = rhs.as_any().downcast_ref::<BooleanColumn>().ok_or_else(|| {
+        ErrorCode::BadArguments("and/or: right column must be Boolean".to_string())
+    })?;
+    let (values, validity) = op(lhs.values(), rhs.values(), lhs_validity, rhs_validity);
+    Ok((Arc::new(BooleanColumn { values }), validity))
 }
 
 impl Column for BooleanColumn {
@@ -123,12 +432,28 @@ impl Column for BooleanColumn {
         chunks
             .by_ref()
             .zip(mask_chunks.by_ref())
-            .for_each(|(chunk, mut mask)| {
-                while mask != 0 {
-                    let n = mask.trailing_zeros() as usize;
-                    let value: bool = chunk & (1 << n) != 0;
-                    bitmap.push(value);
-                    mask = mask & (mask - 1);
+            .for_each(|(chunk, mask_chunk)| {
+                if mask_chunk == 0 {
+                    return;
+                }
+                if mask_chunk == u64::MAX {
+                    bitmap.extend_from_slice(&chunk.to_le_bytes(), 0, 64);
+                    return;
+                }
+                let ones = mask_chunk.count_ones() as usize;
+                let leading_ones = (!mask_chunk).trailing_zeros() as usize;
+                if ones == leading_ones {
+                    // the selected bits of this chunk form a contiguous prefix,
+                    // so the whole run can be copied in one shot.
+                    bitmap.extend_from_slice(&chunk.to_le_bytes(), 0, leading_ones);
+                } else {
+                    let mut mask = mask_chunk;
+                    while mask != 0 {
+                        let n = mask.trailing_zeros() as usize;
+                        let value: bool = chunk & (1 << n) != 0;
+                        bitmap.push(value);
+                        mask = mask & (mask - 1);
+                    }
                 }
             });
 
@@ -221,9 +546,20 @@ impl ScalarColumn for BooleanColumn {
     }
 
     fn from_iterator<'a>(it: impl Iterator<Item = Self::RefItem<'a>>) -> Self {
-        let bitmap = MutableBitmap::from_iter(it);
-        BooleanColumn {
-            values: bitmap.into(),
+        // comparison/predicate kernels produce exact-size iterators of `bool`
+        // results, so assemble the column word-at-a-time instead of pushing
+        // one bit at a time.
+        match it.size_hint() {
+            (len, Some(upper)) if len == upper => {
+                let mut it = it;
+                Self::collect_bool(len, |_| it.next().unwrap())
+            }
+            _ => {
+                let bitmap = MutableBitmap::from_iter(it);
+                BooleanColumn {
+                    values: bitmap.into(),
+                }
+            }
         }
     }
 
@@ -245,3 +581,242 @@ impl std::fmt::Debug for BooleanColumn {
         display_fmt(iter, head, self.len(), self.data_type_id(), f)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_column(values: &[bool]) -> BooleanColumn {
+        BooleanColumn::from_slice(values)
+    }
+
+    #[test]
+    fn test_filter_exact_chunk_boundary() {
+        let values: Vec<bool> = (0..128).map(|i| i % 2 == 0).collect();
+        let mask: Vec<bool> = (0..128).map(|i| i < 64).collect();
+        let column = make_column(&values);
+        let filter = make_column(&mask);
+
+        let filtered = column.filter(&filter);
+        let filtered = filtered.as_any().downcast_ref::<BooleanColumn>().unwrap();
+        let expected: Vec<bool> = values.iter().take(64).cloned().collect();
+        assert_eq!(filtered.iter().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn test_filter_partial_trailing_word() {
+        let values: Vec<bool> = (0..70).map(|i| i % 3 == 0).collect();
+        let mask: Vec<bool> = (0..70).map(|i| i % 2 == 0).collect();
+        let column = make_column(&values);
+        let filter = make_column(&mask);
+
+        let filtered = column.filter(&filter);
+        let filtered = filtered.as_any().downcast_ref::<BooleanColumn>().unwrap();
+        let expected: Vec<bool> = values
+            .iter()
+            .zip(mask.iter())
+            .filter(|(_, m)| **m)
+            .map(|(v, _)| *v)
+            .collect();
+        assert_eq!(filtered.iter().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn test_filter_scattered_mask() {
+        let values: Vec<bool> = (0..64).map(|i| i % 2 == 0).collect();
+        let mask: Vec<bool> = (0..64).map(|i| i % 5 == 0).collect();
+        let column = make_column(&values);
+        let filter = make_column(&mask);
+
+        let filtered = column.filter(&filter);
+        let filtered = filtered.as_any().downcast_ref::<BooleanColumn>().unwrap();
+        let expected: Vec<bool> = values
+            .iter()
+            .zip(mask.iter())
+            .filter(|(_, m)| **m)
+            .map(|(v, _)| *v)
+            .collect();
+        assert_eq!(filtered.iter().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn test_if_then_else_boolean_specialization() {
+        let pred: Vec<bool> = (0..70).map(|i| i % 2 == 0).collect();
+        let truthy: Vec<bool> = (0..70).map(|i| i % 3 == 0).collect();
+        let falsy: Vec<bool> = (0..70).map(|i| i % 5 == 0).collect();
+
+        let pred_col = make_column(&pred);
+        let truthy_col: ColumnRef = make_column(&truthy).arc();
+        let falsy_col: ColumnRef = make_column(&falsy).arc();
+
+        let result = pred_col.if_then_else(&truthy_col, &falsy_col).unwrap();
+        let result = result.as_any().downcast_ref::<BooleanColumn>().unwrap();
+
+        let expected: Vec<bool> = (0..70)
+            .map(|i| if pred[i] { truthy[i] } else { falsy[i] })
+            .collect();
+        assert_eq!(result.iter().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn test_if_then_else_broadcasts_length_one() {
+        let pred = make_column(&[true, false, true, false]);
+        let truthy: ColumnRef = make_column(&[true]).arc();
+        let falsy: ColumnRef = make_column(&[false, true, false, true]).arc();
+
+        let result = pred.if_then_else(&truthy, &falsy).unwrap();
+        let result = result.as_any().downcast_ref::<BooleanColumn>().unwrap();
+        assert_eq!(result.iter().collect::<Vec<_>>(), vec![
+            true, true, true, true
+        ]);
+    }
+
+    #[test]
+    fn test_if_then_else_honors_sliced_offset() {
+        // slicing at a non-byte-aligned offset exercises the bitmap-realign fix.
+        let pred_full = make_column(&(0..16).map(|i| i % 2 == 0).collect::<Vec<_>>());
+        let truthy_full: ColumnRef = make_column(&(0..16).map(|_| true).collect::<Vec<_>>()).arc();
+        let falsy_full: ColumnRef = make_column(&(0..16).map(|_| false).collect::<Vec<_>>()).arc();
+
+        let pred_sliced = pred_full.arc().slice(3, 8);
+        let pred_sliced = pred_sliced.as_any().downcast_ref::<BooleanColumn>().unwrap();
+        let truthy_sliced = truthy_full.slice(3, 8);
+        let falsy_sliced = falsy_full.slice(3, 8);
+
+        let result = pred_sliced
+            .if_then_else(&truthy_sliced, &falsy_sliced)
+            .unwrap();
+        let result = result.as_any().downcast_ref::<BooleanColumn>().unwrap();
+
+        let expected: Vec<bool> = (3..11).map(|i| i % 2 == 0).collect();
+        assert_eq!(result.iter().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn test_column_level_if_then_else_entry_point() {
+        let pred: ColumnRef = make_column(&[true, false]).arc();
+        let truthy: ColumnRef = make_column(&[true, true]).arc();
+        let falsy: ColumnRef = make_column(&[false, false]).arc();
+
+        let result = if_then_else(&pred, &truthy, &falsy).unwrap();
+        let result = result.as_any().downcast_ref::<BooleanColumn>().unwrap();
+        assert_eq!(result.iter().collect::<Vec<_>>(), vec![true, false]);
+    }
+
+    fn bitmap_from(bits: &[bool]) -> Bitmap {
+        MutableBitmap::from_iter(bits.iter().cloned()).into()
+    }
+
+    #[test]
+    fn test_kleene_and_null_propagation() {
+        // TRUE AND NULL = NULL, FALSE AND NULL = FALSE, NULL AND NULL = NULL
+        let lhs = bitmap_from(&[true, false, false]);
+        let rhs = bitmap_from(&[false, false, false]);
+        let lhs_validity = bitmap_from(&[true, true, false]);
+        let rhs_validity = bitmap_from(&[false, false, false]);
+
+        let (values, validity) =
+            BooleanColumn::kleene_and(&lhs, &rhs, Some(&lhs_validity), Some(&rhs_validity));
+        let validity = validity.unwrap();
+
+        assert!(!validity.get_bit(0));
+        assert!(validity.get_bit(1));
+        assert!(!values.get_bit(1));
+        assert!(!validity.get_bit(2));
+    }
+
+    #[test]
+    fn test_kleene_or_null_propagation() {
+        // TRUE OR NULL = TRUE, FALSE OR NULL = NULL, NULL OR NULL = NULL
+        let lhs = bitmap_from(&[true, false, false]);
+        let rhs = bitmap_from(&[false, false, false]);
+        let lhs_validity = bitmap_from(&[true, true, false]);
+        let rhs_validity = bitmap_from(&[false, false, false]);
+
+        let (values, validity) =
+            BooleanColumn::kleene_or(&lhs, &rhs, Some(&lhs_validity), Some(&rhs_validity));
+        let validity = validity.unwrap();
+
+        assert!(validity.get_bit(0));
+        assert!(values.get_bit(0));
+        assert!(!validity.get_bit(1));
+        assert!(!validity.get_bit(2));
+    }
+
+    #[test]
+    fn test_kleene_and_non_nullable_side() {
+        // a side with no validity bitmap is always valid, so FALSE still
+        // dominates a NULL on the other side.
+        let lhs = bitmap_from(&[true, false]);
+        let rhs = bitmap_from(&[false, false]);
+        let rhs_validity = bitmap_from(&[false, false]);
+
+        let (values, validity) = BooleanColumn::kleene_and(&lhs, &rhs, None, Some(&rhs_validity));
+        let validity = validity.unwrap();
+
+        assert!(!validity.get_bit(0));
+        assert!(validity.get_bit(1));
+        assert!(!values.get_bit(1));
+    }
+
+    #[test]
+    fn test_kleene_not() {
+        let values = bitmap_from(&[true, false, true]);
+        let result = BooleanColumn::kleene_not(&values);
+        assert!(!result.get_bit(0));
+        assert!(result.get_bit(1));
+        assert!(!result.get_bit(2));
+    }
+
+    #[test]
+    fn test_column_level_and_or_not_wiring() {
+        let lhs: ColumnRef = make_column(&[true, false]).arc();
+        let rhs: ColumnRef = make_column(&[false, false]).arc();
+        let rhs_validity = bitmap_from(&[false, false]);
+
+        let (result, validity) = and(&lhs, None, &rhs, Some(&rhs_validity)).unwrap();
+        let result = result.as_any().downcast_ref::<BooleanColumn>().unwrap();
+        let validity = validity.unwrap();
+        assert!(!validity.get_bit(0));
+        assert!(validity.get_bit(1));
+        assert!(!result.values().get_bit(1));
+
+        let (result, validity) = or(&lhs, None, &rhs, Some(&rhs_validity)).unwrap();
+        let result = result.as_any().downcast_ref::<BooleanColumn>().unwrap();
+        let validity = validity.unwrap();
+        assert!(validity.get_bit(0));
+        assert!(result.values().get_bit(0));
+        assert!(!validity.get_bit(1));
+
+        let not_lhs = not(&lhs).unwrap();
+        let not_lhs = not_lhs.as_any().downcast_ref::<BooleanColumn>().unwrap();
+        assert!(!not_lhs.values().get_bit(0));
+        assert!(not_lhs.values().get_bit(1));
+    }
+
+    #[test]
+    fn test_collect_bool_exact_chunk_length() {
+        let length = 64;
+        let predicate = |i: usize| i % 2 == 0;
+        let column = BooleanColumn::collect_bool(length, predicate);
+        let expected: Vec<bool> = (0..length).map(predicate).collect();
+        assert_eq!(column.iter().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn test_collect_bool_with_trailing_remainder() {
+        // 130 = 2 full 64-bit words plus a 2-bit tail.
+        let length = 130;
+        let predicate = |i: usize| i % 7 == 0;
+        let column = BooleanColumn::collect_bool(length, predicate);
+        let expected: Vec<bool> = (0..length).map(predicate).collect();
+        assert_eq!(column.iter().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn test_from_iterator_matches_naive_construction() {
+        let values = vec![true, false, true, true, false, false, true];
+        let column = BooleanColumn::from_iterator(values.iter().cloned());
+        assert_eq!(column.iter().collect::<Vec<_>>(), values);
+    }
+}